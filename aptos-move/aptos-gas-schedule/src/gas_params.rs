@@ -0,0 +1,11 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Flat re-export of the per-category gas parameters so natives can
+//! `use aptos_gas_schedule::gas_params::natives::aptos_framework::*;`.
+
+pub mod natives {
+    pub mod aptos_framework {
+        pub use crate::gas_schedule::aptos_framework::*;
+    }
+}
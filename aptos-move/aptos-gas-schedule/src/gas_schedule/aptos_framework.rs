@@ -0,0 +1,32 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Gas parameters for the RLP, Keccak, and SSZ natives
+//! (`aptos_framework::rlp`, `aptos_framework::keccak`, `aptos_framework::ssz`),
+//! declared through the same versioned `define_gas_parameters!` list as the
+//! rest of `aptos_framework`'s native gas parameters (e.g.
+//! `object::exists_at`), rather than as untracked floating constants.
+
+use crate::{define_gas_parameters, gas_schedule::macros::RLP_KECCAK_SSZ_GAS_FEATURE_VERSION};
+use aptos_gas_algebra::{InternalGas, InternalGasPerByte};
+
+define_gas_parameters!(
+    "aptos_framework",
+    RLP_KECCAK_SSZ_GAS_FEATURE_VERSION,
+    [
+        [RLP_ENCODE_BASE: InternalGas, "rlp_encode.base", 1_000],
+        [RLP_ENCODE_PER_BYTE: InternalGasPerByte, "rlp_encode.per_byte", 20],
+        [RLP_DECODE_BASE: InternalGas, "rlp_decode.base", 1_000],
+        [RLP_DECODE_PER_BYTE: InternalGasPerByte, "rlp_decode.per_byte", 20],
+
+        [KECCAK256_BASE: InternalGas, "keccak256.base", 1_000],
+        [KECCAK256_PER_BYTE: InternalGasPerByte, "keccak256.per_byte", 15],
+
+        [SSZ_ENCODE_BASE: InternalGas, "ssz_encode.base", 1_000],
+        [SSZ_ENCODE_PER_BYTE: InternalGasPerByte, "ssz_encode.per_byte", 20],
+        [SSZ_DECODE_BASE: InternalGas, "ssz_decode.base", 1_000],
+        [SSZ_DECODE_PER_BYTE: InternalGasPerByte, "ssz_decode.per_byte", 20],
+        [SSZ_HASH_TREE_ROOT_BASE: InternalGas, "ssz_hash_tree_root.base", 1_000],
+        [SSZ_HASH_TREE_ROOT_PER_BYTE: InternalGasPerByte, "ssz_hash_tree_root.per_byte", 15],
+    ]
+);
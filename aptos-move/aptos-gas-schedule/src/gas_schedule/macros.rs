@@ -0,0 +1,23 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! `define_gas_parameters!` declares a native's gas parameters as a single
+//! versioned, named list instead of untracked floating constants: every
+//! parameter records the on-chain gas schedule key it publishes under
+//! (`"<category>.<key>"`) and the gas feature version at which it became
+//! chargeable, alongside its default value.
+
+/// Gas feature version at which the RLP/Keccak/SSZ native gas parameters
+/// were introduced. Natives charging against parameters gated behind a
+/// version should not be reachable below it.
+pub const RLP_KECCAK_SSZ_GAS_FEATURE_VERSION: u64 = 11;
+
+#[macro_export]
+macro_rules! define_gas_parameters {
+    ($category:literal, $gas_feature_version:expr, [$([$name:ident: $ty:ty, $key:literal, $value:expr]),* $(,)?]) => {
+        $(
+            #[doc = concat!("`", $category, ".", $key, "` on the on-chain gas schedule, chargeable from gas feature version ", stringify!($gas_feature_version), ".")]
+            pub const $name: $ty = <$ty>::new($value);
+        )*
+    };
+}
@@ -0,0 +1,7 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+pub mod macros;
+
+pub mod aptos_framework;
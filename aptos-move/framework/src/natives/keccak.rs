@@ -0,0 +1,114 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_gas_algebra::NumBytes;
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_assert_eq, safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext,
+    SafeNativeError, SafeNativeResult,
+};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use sha3::{Digest, Keccak256};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// `eth_address_from_pubkey` was not given a 64-byte uncompressed
+/// secp256k1 public key.
+const E_INVALID_PUBKEY_LENGTH: u64 = 1;
+
+fn keccak256_bytes(input: &[u8]) -> Vec<u8> {
+    Keccak256::digest(input).to_vec()
+}
+
+/// Derives an Ethereum-style account address from a 64-byte uncompressed
+/// secp256k1 public key: Keccak-256 the key, keep the last 20 bytes.
+fn eth_address_from_pubkey_bytes(pubkey: &[u8]) -> Result<Vec<u8>, u64> {
+    if pubkey.len() != 64 {
+        return Err(E_INVALID_PUBKEY_LENGTH);
+    }
+    Ok(Keccak256::digest(pubkey)[12..].to_vec())
+}
+
+fn native_keccak256(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 1);
+
+    let input = safely_pop_arg!(args, Vec<u8>);
+
+    context
+        .charge(KECCAK256_BASE + KECCAK256_PER_BYTE * NumBytes::new(input.len() as u64))?;
+
+    Ok(smallvec![Value::vector_u8(keccak256_bytes(&input))])
+}
+
+fn native_eth_address_from_pubkey(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 1);
+
+    let pubkey = safely_pop_arg!(args, Vec<u8>);
+
+    context
+        .charge(KECCAK256_BASE + KECCAK256_PER_BYTE * NumBytes::new(pubkey.len() as u64))?;
+
+    let address = eth_address_from_pubkey_bytes(&pubkey)
+        .map_err(|abort_code| SafeNativeError::Abort { abort_code })?;
+    Ok(smallvec![Value::vector_u8(address)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        ("keccak256", native_keccak256 as RawSafeNative),
+        ("eth_address_from_pubkey", native_eth_address_from_pubkey),
+    ];
+
+    builder.make_named_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keccak256_matches_known_vector() {
+        // keccak256("") (the original Keccak, not NIST SHA3-256), the
+        // well-known Ethereum empty-input digest.
+        let expected: [u8; 32] = [
+            0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7,
+            0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04,
+            0x5d, 0x85, 0xa4, 0x70,
+        ];
+        assert_eq!(keccak256_bytes(&[]), expected.to_vec());
+    }
+
+    #[test]
+    fn eth_address_from_pubkey_round_trip_length() {
+        let pubkey = [0x11u8; 64];
+        let address = eth_address_from_pubkey_bytes(&pubkey).unwrap();
+        assert_eq!(address.len(), 20);
+        assert_eq!(address, keccak256_bytes(&pubkey)[12..]);
+    }
+
+    #[test]
+    fn eth_address_from_pubkey_rejects_wrong_length() {
+        let pubkey = [0x11u8; 63];
+        assert_eq!(
+            eth_address_from_pubkey_bytes(&pubkey),
+            Err(E_INVALID_PUBKEY_LENGTH)
+        );
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod keccak;
+pub mod rlp;
+pub mod ssz;
+
+use aptos_native_interface::SafeNativeBuilder;
+use move_core_types::account_address::AccountAddress;
+use move_vm_runtime::native_functions::NativeFunctionTable;
+
+/// Registers the natives backing `aptos_framework::{rlp, keccak, ssz}`
+/// under `framework_addr`, for merging into the VM's overall native
+/// function table alongside the rest of `aptos_framework`'s natives.
+pub fn all_natives(framework_addr: AccountAddress, builder: &SafeNativeBuilder) -> NativeFunctionTable {
+    let mut natives = vec![];
+
+    macro_rules! add_natives_from_module {
+        ($module_name:literal, $natives:expr) => {
+            natives.extend(
+                $natives
+                    .map(|(func_name, func)| (framework_addr, $module_name.to_string(), func_name, func)),
+            );
+        };
+    }
+
+    add_natives_from_module!("rlp", rlp::make_all(builder));
+    add_natives_from_module!("keccak", keccak::make_all(builder));
+    add_natives_from_module!("ssz", ssz::make_all(builder));
+
+    natives
+}
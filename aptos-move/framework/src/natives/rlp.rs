@@ -1,12 +1,13 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use aptos_gas_algebra::NumBytes;
 use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
 use aptos_native_interface::{
     safely_assert_eq, safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext,
     SafeNativeError, SafeNativeResult,
 };
-use aptos_types::vm_status::sub_status::NFE_BCS_SERIALIZATION_FAILURE;
+use move_core_types::value::MoveTypeLayout;
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{
     loaded_data::runtime_types::Type,
@@ -15,6 +16,284 @@ use move_vm_types::{
 use smallvec::{smallvec, SmallVec};
 use std::collections::VecDeque;
 
+/// The RLP input was truncated or contained an invalid length prefix.
+const E_RLP_MALFORMED_INPUT: u64 = 1;
+/// The decoded RLP structure does not fit the requested Move type layout.
+const E_RLP_TYPE_MISMATCH: u64 = 2;
+/// Bytes remained in the input after a complete RLP item was decoded.
+const E_RLP_TRAILING_BYTES: u64 = 3;
+
+/***************************************************************************************************
+ * raw RLP list codec
+ *
+ * Minimal encoder/decoder for the subset of RLP needed by EIP-2718 typed
+ * transaction envelopes: a top-level list of byte-string (or nested-list)
+ * fields. Nested list fields are handed back to Move as their own opaque
+ * RLP-encoded span rather than being recursively decoded.
+ **************************************************************************************************/
+
+fn rlp_length_prefix(short_base: u8, long_base: u8, len: usize) -> Vec<u8> {
+    if len < 56 {
+        vec![short_base + len as u8]
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let first_nonzero = len_bytes.iter().position(|&b| b != 0).unwrap();
+        let len_bytes = &len_bytes[first_nonzero..];
+        let mut out = vec![long_base + len_bytes.len() as u8];
+        out.extend_from_slice(len_bytes);
+        out
+    }
+}
+
+fn rlp_encode_item(item: &[u8]) -> Vec<u8> {
+    if item.len() == 1 && item[0] < 0x80 {
+        return item.to_vec();
+    }
+    let mut out = rlp_length_prefix(0x80, 0xb7, item.len());
+    out.extend_from_slice(item);
+    out
+}
+
+fn rlp_encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let body: Vec<u8> = items.iter().flat_map(|item| rlp_encode_item(item)).collect();
+    let mut out = rlp_length_prefix(0xc0, 0xf7, body.len());
+    out.extend_from_slice(&body);
+    out
+}
+
+fn rlp_be_bytes_to_len(bytes: &[u8]) -> Option<usize> {
+    if bytes.is_empty() || bytes.len() > std::mem::size_of::<usize>() {
+        return None;
+    }
+    let mut buf = [0u8; std::mem::size_of::<usize>()];
+    buf[std::mem::size_of::<usize>() - bytes.len()..].copy_from_slice(bytes);
+    Some(usize::from_be_bytes(buf))
+}
+
+/// Reads the header at the start of `bytes` and returns
+/// `(is_list, payload_start, total_consumed)`. Returns `None` (rather than
+/// wrapping) if the attacker-controlled length overflows `usize`.
+fn rlp_read_header(bytes: &[u8]) -> Option<(bool, usize, usize)> {
+    let first = *bytes.first()?;
+    match first {
+        0x00..=0x7f => Some((false, 0, 1)),
+        0x80..=0xb7 => {
+            let len = (first - 0x80) as usize;
+            let total = 1usize.checked_add(len)?;
+            (bytes.len() >= total).then_some((false, 1, total))
+        },
+        0xb8..=0xbf => {
+            let len_of_len = (first - 0xb7) as usize;
+            let len = rlp_be_bytes_to_len(bytes.get(1..1 + len_of_len)?)?;
+            let payload_start = 1usize.checked_add(len_of_len)?;
+            let total = payload_start.checked_add(len)?;
+            (bytes.len() >= total).then_some((false, payload_start, total))
+        },
+        0xc0..=0xf7 => {
+            let len = (first - 0xc0) as usize;
+            let total = 1usize.checked_add(len)?;
+            (bytes.len() >= total).then_some((true, 1, total))
+        },
+        0xf8..=0xff => {
+            let len_of_len = (first - 0xf7) as usize;
+            let len = rlp_be_bytes_to_len(bytes.get(1..1 + len_of_len)?)?;
+            let payload_start = 1usize.checked_add(len_of_len)?;
+            let total = payload_start.checked_add(len)?;
+            (bytes.len() >= total).then_some((true, payload_start, total))
+        },
+    }
+}
+
+/// Why [`rlp_parse_list`] failed, so callers can surface the right abort
+/// code instead of collapsing every failure into one.
+#[derive(Debug)]
+enum RlpListParseError {
+    /// Not a single well-formed top-level list (bad header, truncated
+    /// item, or a header whose length runs past the end of the buffer).
+    Malformed,
+    /// The list was well-formed but didn't consume the whole buffer.
+    TrailingBytes,
+}
+
+/// Parses `bytes` as a single top-level RLP list, returning each field's
+/// raw bytes (header-stripped for byte-strings, header-included for nested
+/// lists).
+fn rlp_parse_list(bytes: &[u8]) -> Result<Vec<Vec<u8>>, RlpListParseError> {
+    let (is_list, payload_start, total) =
+        rlp_read_header(bytes).ok_or(RlpListParseError::Malformed)?;
+    if !is_list {
+        return Err(RlpListParseError::Malformed);
+    }
+    if total != bytes.len() {
+        return Err(RlpListParseError::TrailingBytes);
+    }
+
+    let mut items = Vec::new();
+    let mut cursor = payload_start;
+    while cursor < bytes.len() {
+        let (item_is_list, item_payload_start, item_total) =
+            rlp_read_header(&bytes[cursor..]).ok_or(RlpListParseError::Malformed)?;
+        let item_end = cursor + item_total;
+        let field = if item_is_list {
+            bytes[cursor..item_end].to_vec()
+        } else {
+            bytes[cursor + item_payload_start..item_end].to_vec()
+        };
+        items.push(field);
+        cursor = item_end;
+    }
+    Ok(items)
+}
+
+/***************************************************************************************************
+ * type-layout-aware RLP decoding
+ *
+ * RLP and BCS are different wire formats (RLP: big-endian minimal-width
+ * integers, no vector-length prefix, list framing via the header alone;
+ * BCS: little-endian fixed-width integers, ULEB128 vector-length prefix,
+ * no framing at all). So decoding `T` from RLP means recursively walking
+ * `T`'s type layout against the RLP structure — RLP lists become
+ * struct/vector fields, RLP byte-strings become primitive fields — and
+ * producing the *matching BCS* encoding for each field as we go. Once the
+ * whole value has been converted this way, the result is genuine BCS and
+ * `Value::simple_deserialize` is the right (and safe) tool to pack it.
+ **************************************************************************************************/
+
+#[derive(Debug)]
+enum RlpLayoutError {
+    Malformed,
+    TrailingBytes,
+    TypeMismatch,
+}
+
+impl From<RlpLayoutError> for u64 {
+    fn from(e: RlpLayoutError) -> u64 {
+        match e {
+            RlpLayoutError::Malformed => E_RLP_MALFORMED_INPUT,
+            RlpLayoutError::TrailingBytes => E_RLP_TRAILING_BYTES,
+            RlpLayoutError::TypeMismatch => E_RLP_TYPE_MISMATCH,
+        }
+    }
+}
+
+fn uleb128_encode(mut len: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let mut byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+    out
+}
+
+/// Splits the payload of an already-unwrapped RLP list into its top-level
+/// items, keeping each item's own header (so it can be fed back into
+/// [`rlp_decode_value`] or [`rlp_read_header`]).
+fn rlp_split_items(payload: &[u8]) -> Result<Vec<Vec<u8>>, RlpLayoutError> {
+    let mut items = Vec::new();
+    let mut cursor = 0;
+    while cursor < payload.len() {
+        let (_, _, item_total) =
+            rlp_read_header(&payload[cursor..]).ok_or(RlpLayoutError::Malformed)?;
+        items.push(payload[cursor..cursor + item_total].to_vec());
+        cursor += item_total;
+    }
+    Ok(items)
+}
+
+/// Recursively decodes `bytes` (one complete RLP item, header included)
+/// against `layout`, returning the equivalent BCS encoding.
+fn rlp_decode_value(bytes: &[u8], layout: &MoveTypeLayout) -> Result<Vec<u8>, RlpLayoutError> {
+    let (is_list, payload_start, total) =
+        rlp_read_header(bytes).ok_or(RlpLayoutError::Malformed)?;
+    if total != bytes.len() {
+        return Err(RlpLayoutError::TrailingBytes);
+    }
+    let payload = &bytes[payload_start..total];
+
+    match layout {
+        MoveTypeLayout::Bool => match (is_list, payload) {
+            (false, []) => Ok(vec![0]),
+            (false, [1]) => Ok(vec![1]),
+            _ => Err(RlpLayoutError::TypeMismatch),
+        },
+        MoveTypeLayout::U8
+        | MoveTypeLayout::U16
+        | MoveTypeLayout::U32
+        | MoveTypeLayout::U64
+        | MoveTypeLayout::U128
+        | MoveTypeLayout::U256 => {
+            let width = match layout {
+                MoveTypeLayout::U8 => 1,
+                MoveTypeLayout::U16 => 2,
+                MoveTypeLayout::U32 => 4,
+                MoveTypeLayout::U64 => 8,
+                MoveTypeLayout::U128 => 16,
+                MoveTypeLayout::U256 => 32,
+                _ => unreachable!(),
+            };
+            // RLP integers are big-endian and minimal-width (no leading
+            // zero byte); re-emit as BCS's little-endian fixed width.
+            if is_list || payload.len() > width || payload.first() == Some(&0) {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            let mut le = vec![0u8; width];
+            for (i, byte) in payload.iter().rev().enumerate() {
+                le[i] = *byte;
+            }
+            Ok(le)
+        },
+        MoveTypeLayout::Address => {
+            if is_list || payload.len() != move_core_types::account_address::AccountAddress::LENGTH
+            {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            Ok(payload.to_vec())
+        },
+        MoveTypeLayout::Vector(elem) if matches!(**elem, MoveTypeLayout::U8) => {
+            if is_list {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            let mut out = uleb128_encode(payload.len());
+            out.extend_from_slice(payload);
+            Ok(out)
+        },
+        MoveTypeLayout::Vector(elem) => {
+            if !is_list {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            let items = rlp_split_items(payload)?;
+            let mut out = uleb128_encode(items.len());
+            for item in &items {
+                out.extend_from_slice(&rlp_decode_value(item, elem)?);
+            }
+            Ok(out)
+        },
+        MoveTypeLayout::Struct(struct_layout) => {
+            if !is_list {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            let field_layouts = struct_layout.fields(None);
+            let items = rlp_split_items(payload)?;
+            if items.len() != field_layouts.len() {
+                return Err(RlpLayoutError::TypeMismatch);
+            }
+            let mut out = Vec::new();
+            for (item, field_layout) in items.iter().zip(field_layouts.iter()) {
+                out.extend_from_slice(&rlp_decode_value(item, field_layout)?);
+            }
+            Ok(out)
+        },
+        _ => Err(RlpLayoutError::TypeMismatch),
+    }
+}
+
 fn native_encode(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
@@ -24,11 +303,39 @@ fn native_encode(
     safely_assert_eq!(args.len(), 1);
 
     let v = safely_pop_arg!(args, Reference);
+    let val = v.read_ref()?;
 
-    context.charge(OBJECT_EXISTS_AT_BASE)?;
+    let bytes = val.copy_value()?.value_as::<Vec<u8>>()?;
+    let encoded = rlp_encode_item(&bytes);
+    context.charge(RLP_ENCODE_BASE + RLP_ENCODE_PER_BYTE * NumBytes::new(encoded.len() as u64))?;
 
+    Ok(smallvec![Value::vector_u8(encoded)])
+}
+
+/// Metered RLP-decode of the value behind `v` into its raw decoded byte
+/// buffer, for callers that want the bytes as-is rather than a value
+/// reconstructed under a type layout (see [`rlp_decode_value`] for that).
+fn rlp_decode_metered(context: &mut SafeNativeContext, v: Reference) -> SafeNativeResult<Vec<u8>> {
     let val = v.read_ref()?;
-    Ok(smallvec![Value::vector_u8(val.rlp_encode())])
+    let bytes = val.copy_value()?.value_as::<Vec<u8>>()?;
+
+    // Bound the cost of the input up front, mirroring the per-byte charge on
+    // the way out once the real decoded size is known.
+    context.charge(RLP_DECODE_BASE + RLP_DECODE_PER_BYTE * NumBytes::new(bytes.len() as u64))?;
+
+    let (_, payload_start, total) = rlp_read_header(&bytes).ok_or(SafeNativeError::Abort {
+        abort_code: E_RLP_MALFORMED_INPUT,
+    })?;
+    if total != bytes.len() {
+        return Err(SafeNativeError::Abort {
+            abort_code: E_RLP_TRAILING_BYTES,
+        });
+    }
+    let buffer = bytes[payload_start..total].to_vec();
+
+    context.charge(RLP_DECODE_PER_BYTE * NumBytes::new(buffer.len() as u64))?;
+
+    Ok(buffer)
 }
 
 fn native_decode(
@@ -40,25 +347,119 @@ fn native_decode(
     safely_assert_eq!(args.len(), 1);
 
     let val_type: Type = ty_args.pop().unwrap();
-    let val = safely_pop_arg!(args, Reference);
+    let v = safely_pop_arg!(args, Reference);
+    let val = v.read_ref()?;
 
-    let val = val.read_ref()?;
+    let bytes = val.copy_value()?.value_as::<Vec<u8>>()?;
+    context.charge(RLP_DECODE_BASE + RLP_DECODE_PER_BYTE * NumBytes::new(bytes.len() as u64))?;
 
-    context.charge(OBJECT_EXISTS_AT_BASE)?;
+    let layout = context.type_to_type_layout(&val_type)?;
+    let bcs_bytes = rlp_decode_value(&bytes, &layout).map_err(|e| SafeNativeError::Abort {
+        abort_code: e.into(),
+    })?;
 
-    let mut buffer: Vec<u8> = vec![];
-    match val.rlp_decode(&mut buffer) {
-        Some(buffer) => buffer,
-        None => {
-            return Err(SafeNativeError::Abort {
-                abort_code: NFE_BCS_SERIALIZATION_FAILURE,
-            })
-        },
-    };
+    context.charge(RLP_DECODE_PER_BYTE * NumBytes::new(bcs_bytes.len() as u64))?;
+
+    let decoded = Value::simple_deserialize(&bcs_bytes, &layout).ok_or(SafeNativeError::Abort {
+        abort_code: E_RLP_TYPE_MISMATCH,
+    })?;
+
+    Ok(smallvec![decoded])
+}
+
+/// Byte-returning variant of [`native_decode`] for callers that want the
+/// raw decoded buffer instead of a reconstructed `T`.
+fn native_decode_bytes(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 1);
+    safely_assert_eq!(args.len(), 1);
+
+    let v = safely_pop_arg!(args, Reference);
+    let buffer = rlp_decode_metered(context, v)?;
 
     Ok(smallvec![Value::vector_u8(buffer)])
 }
 
+fn native_encode_typed_tx(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 2);
+
+    let fields = safely_pop_arg!(args, Vec<Vec<u8>>);
+    let type_byte = safely_pop_arg!(args, u8);
+
+    let list = rlp_encode_list(&fields);
+    let encoded = if type_byte == 0 {
+        list
+    } else {
+        let mut out = Vec::with_capacity(1 + list.len());
+        out.push(type_byte);
+        out.extend_from_slice(&list);
+        out
+    };
+
+    context.charge(RLP_ENCODE_BASE + RLP_ENCODE_PER_BYTE * NumBytes::new(encoded.len() as u64))?;
+
+    Ok(smallvec![Value::vector_u8(encoded)])
+}
+
+/// Decodes an EIP-2718 typed transaction envelope: a legacy (bare RLP
+/// list) transaction if the leading byte is in `[0xc0, 0xff]`, otherwise a
+/// typed transaction whose leading byte is the type and whose remainder is
+/// an RLP list of fields.
+fn native_decode_typed_tx(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 1);
+
+    let bytes = safely_pop_arg!(args, Vec<u8>);
+
+    context.charge(RLP_DECODE_BASE + RLP_DECODE_PER_BYTE * NumBytes::new(bytes.len() as u64))?;
+
+    let first = *bytes.first().ok_or(SafeNativeError::Abort {
+        abort_code: E_RLP_MALFORMED_INPUT,
+    })?;
+
+    let (type_byte, list_bytes) = if (0xc0..=0xff).contains(&first) {
+        (0u8, bytes.as_slice())
+    } else {
+        (first, &bytes[1..])
+    };
+
+    let fields = rlp_parse_list(list_bytes).map_err(|e| SafeNativeError::Abort {
+        abort_code: match e {
+            RlpListParseError::Malformed => E_RLP_MALFORMED_INPUT,
+            RlpListParseError::TrailingBytes => E_RLP_TRAILING_BYTES,
+        },
+    })?;
+
+    // Build the `vector<vector<u8>>` return value through the same
+    // BCS-layout-driven constructor `native_decode` uses, rather than
+    // reaching for a generic-vector helper that isn't meant for
+    // production natives.
+    let fields_layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Vector(Box::new(
+        MoveTypeLayout::U8,
+    ))));
+    let fields_bytes = bcs::to_bytes(&fields).map_err(|_| SafeNativeError::Abort {
+        abort_code: E_RLP_MALFORMED_INPUT,
+    })?;
+    let fields_value =
+        Value::simple_deserialize(&fields_bytes, &fields_layout).ok_or(SafeNativeError::Abort {
+            abort_code: E_RLP_MALFORMED_INPUT,
+        })?;
+
+    Ok(smallvec![Value::u8(type_byte), fields_value])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -69,7 +470,97 @@ pub fn make_all(
     let natives = [
         ("encode", native_encode as RawSafeNative),
         ("decode", native_decode),
+        ("decode_bytes", native_decode_bytes),
+        ("encode_typed_tx", native_encode_typed_tx),
+        ("decode_typed_tx", native_decode_typed_tx),
     ];
 
     builder.make_named_natives(natives)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_round_trip_u64() {
+        let value: u64 = 0x1234;
+        let encoded = rlp_encode_item(&value.to_be_bytes()[6..]);
+        let bcs_bytes = rlp_decode_value(&encoded, &MoveTypeLayout::U64).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&value).unwrap());
+    }
+
+    #[test]
+    fn encode_decode_round_trip_vector_u8() {
+        let bytes = vec![1u8, 2, 3, 4, 5];
+        let encoded = rlp_encode_item(&bytes);
+        let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
+        let bcs_bytes = rlp_decode_value(&encoded, &layout).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn encode_decode_round_trip_vector_of_byte_strings() {
+        let items = vec![vec![1u8, 2, 3], vec![4u8, 5]];
+        let encoded = rlp_encode_list(&items);
+        let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Vector(Box::new(
+            MoveTypeLayout::U8,
+        ))));
+        let bcs_bytes = rlp_decode_value(&encoded, &layout).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&items).unwrap());
+    }
+
+    #[test]
+    fn typed_tx_round_trip() {
+        let fields = vec![vec![1u8, 2], vec![0xaa, 0xbb, 0xcc]];
+        let list = rlp_encode_list(&fields);
+        let mut encoded = vec![2u8];
+        encoded.extend_from_slice(&list);
+
+        let (type_byte, list_bytes) = if (0xc0..=0xff).contains(&encoded[0]) {
+            (0u8, encoded.as_slice())
+        } else {
+            (encoded[0], &encoded[1..])
+        };
+        assert_eq!(type_byte, 2);
+        let decoded = rlp_parse_list(list_bytes).ok().unwrap();
+        assert_eq!(decoded, fields);
+    }
+
+    #[test]
+    fn read_header_rejects_truncated_long_string() {
+        // 0xb9 announces a 2-byte length field, but none follows.
+        assert!(rlp_read_header(&[0xb9]).is_none());
+    }
+
+    #[test]
+    fn read_header_rejects_overflowing_length() {
+        // 0xbf announces an 8-byte length field of all 0xff, which would
+        // overflow any buffer; must return None rather than panicking or
+        // wrapping.
+        let mut bytes = vec![0xbf];
+        bytes.extend_from_slice(&[0xffu8; 8]);
+        assert!(rlp_read_header(&bytes).is_none());
+    }
+
+    #[test]
+    fn parse_list_reports_trailing_bytes_distinctly() {
+        let list = rlp_encode_list(&[vec![1u8]]);
+        let mut with_trailer = list.clone();
+        with_trailer.push(0x00);
+        match rlp_parse_list(&with_trailer) {
+            Err(RlpListParseError::TrailingBytes) => {},
+            other => panic!("expected TrailingBytes, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn decode_value_rejects_type_mismatch() {
+        // A list where a u64 was expected.
+        let encoded = rlp_encode_list(&[vec![1u8]]);
+        match rlp_decode_value(&encoded, &MoveTypeLayout::U64) {
+            Err(RlpLayoutError::TypeMismatch) => {},
+            other => panic!("expected TypeMismatch, got {:?}", other.map(|_| ())),
+        }
+    }
+}
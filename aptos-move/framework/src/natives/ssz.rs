@@ -0,0 +1,604 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_gas_algebra::NumBytes;
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_assert_eq, safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext,
+    SafeNativeError, SafeNativeResult,
+};
+use move_core_types::value::MoveTypeLayout;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Reference, Value},
+};
+use sha2::{Digest, Sha256};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// `ssz_encode`/`ssz_decode`/`ssz_hash_tree_root` were given a type layout
+/// with no SSZ mapping (e.g. a signer).
+const E_SSZ_UNSUPPORTED_TYPE: u64 = 1;
+/// The SSZ input was shorter than the layout's fixed section, or an
+/// offset pointed outside the buffer.
+const E_SSZ_MALFORMED_INPUT: u64 = 2;
+
+/***************************************************************************************************
+ * layout <-> SSZ shape
+ **************************************************************************************************/
+
+/// Whether `layout` serializes to a fixed-size SSZ part. Move's
+/// `vector<T>` always maps to an SSZ `List` (variable-size), matching how
+/// the framework already treats `vector<u8>` as dynamically sized BCS.
+fn ssz_is_fixed(layout: &MoveTypeLayout) -> bool {
+    match layout {
+        MoveTypeLayout::Bool
+        | MoveTypeLayout::U8
+        | MoveTypeLayout::U16
+        | MoveTypeLayout::U32
+        | MoveTypeLayout::U64
+        | MoveTypeLayout::U128
+        | MoveTypeLayout::U256
+        | MoveTypeLayout::Address => true,
+        MoveTypeLayout::Vector(_) => false,
+        MoveTypeLayout::Struct(s) => s.fields(None).iter().all(ssz_is_fixed),
+        _ => false,
+    }
+}
+
+/***************************************************************************************************
+ * encode
+ **************************************************************************************************/
+
+fn ssz_encode_value(val: &Value, layout: &MoveTypeLayout) -> SafeNativeResult<Vec<u8>> {
+    match layout {
+        MoveTypeLayout::Bool => Ok(vec![val.copy_value()?.value_as::<bool>()? as u8]),
+        MoveTypeLayout::U8 => Ok(vec![val.copy_value()?.value_as::<u8>()?]),
+        MoveTypeLayout::U16 => Ok(val.copy_value()?.value_as::<u16>()?.to_le_bytes().to_vec()),
+        MoveTypeLayout::U32 => Ok(val.copy_value()?.value_as::<u32>()?.to_le_bytes().to_vec()),
+        MoveTypeLayout::U64 => Ok(val.copy_value()?.value_as::<u64>()?.to_le_bytes().to_vec()),
+        MoveTypeLayout::U128 => Ok(val.copy_value()?.value_as::<u128>()?.to_le_bytes().to_vec()),
+        MoveTypeLayout::U256 => Ok(val
+            .copy_value()?
+            .value_as::<move_core_types::u256::U256>()?
+            .to_le_bytes()
+            .to_vec()),
+        MoveTypeLayout::Address => Ok(val
+            .copy_value()?
+            .value_as::<move_core_types::account_address::AccountAddress>()?
+            .to_vec()),
+        MoveTypeLayout::Vector(elem_layout) => {
+            let parts = ssz_encode_vector_parts(val, elem_layout)?;
+            Ok(ssz_serialize_parts(&parts))
+        },
+        MoveTypeLayout::Struct(struct_layout) => {
+            let field_layouts = struct_layout.fields(None);
+            let fields = val.copy_value()?.value_as::<Vec<Value>>()?;
+            let parts = fields
+                .iter()
+                .zip(field_layouts.iter())
+                .map(|(f, fl)| Ok((ssz_is_fixed(fl), ssz_encode_value(f, fl)?)))
+                .collect::<SafeNativeResult<Vec<_>>>()?;
+            Ok(ssz_serialize_parts(&parts))
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: E_SSZ_UNSUPPORTED_TYPE,
+        }),
+    }
+}
+
+/// Encodes the elements of `vector<elem_layout>` as `(is_fixed, bytes)`
+/// parts, reading them out of the Move VM's container representation for
+/// `elem_layout`. Primitive element types (`u8`, `u64`, `address`, …) live
+/// in their own specialized vector containers (`VecU8`, `VecU64`, …), not
+/// the generic `Vec<Value>` container used for `vector<struct>` and
+/// `vector<vector<_>>` — mirrors the per-primitive-type handling in
+/// `rlp_decode_value`'s `vector<u8>` case.
+fn ssz_encode_vector_parts(
+    val: &Value,
+    elem_layout: &MoveTypeLayout,
+) -> SafeNativeResult<Vec<(bool, Vec<u8>)>> {
+    let is_fixed = ssz_is_fixed(elem_layout);
+    match elem_layout {
+        MoveTypeLayout::Bool => Ok(val
+            .copy_value()?
+            .value_as::<Vec<bool>>()?
+            .into_iter()
+            .map(|e| (is_fixed, vec![e as u8]))
+            .collect()),
+        MoveTypeLayout::U8 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<u8>>()?
+            .into_iter()
+            .map(|e| (is_fixed, vec![e]))
+            .collect()),
+        MoveTypeLayout::U16 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<u16>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_le_bytes().to_vec()))
+            .collect()),
+        MoveTypeLayout::U32 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<u32>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_le_bytes().to_vec()))
+            .collect()),
+        MoveTypeLayout::U64 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<u64>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_le_bytes().to_vec()))
+            .collect()),
+        MoveTypeLayout::U128 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<u128>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_le_bytes().to_vec()))
+            .collect()),
+        MoveTypeLayout::U256 => Ok(val
+            .copy_value()?
+            .value_as::<Vec<move_core_types::u256::U256>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_le_bytes().to_vec()))
+            .collect()),
+        MoveTypeLayout::Address => Ok(val
+            .copy_value()?
+            .value_as::<Vec<move_core_types::account_address::AccountAddress>>()?
+            .into_iter()
+            .map(|e| (is_fixed, e.to_vec()))
+            .collect()),
+        _ => {
+            let elems = val.copy_value()?.value_as::<Vec<Value>>()?;
+            elems
+                .iter()
+                .map(|e| Ok((is_fixed, ssz_encode_value(e, elem_layout)?)))
+                .collect::<SafeNativeResult<Vec<_>>>()
+        },
+    }
+}
+
+/// Lays out a container's (struct or list) already-encoded parts using the
+/// standard SSZ scheme: fixed parts inline, variable parts replaced by a
+/// 4-byte little-endian offset (from the start of the container) with
+/// their bodies appended afterward in order.
+fn ssz_serialize_parts(parts: &[(bool, Vec<u8>)]) -> Vec<u8> {
+    let fixed_len: usize = parts
+        .iter()
+        .map(|(is_fixed, bytes)| if *is_fixed { bytes.len() } else { 4 })
+        .sum();
+
+    let mut fixed = Vec::with_capacity(fixed_len);
+    let mut variable = Vec::new();
+    let mut offset = fixed_len;
+    for (is_fixed, bytes) in parts {
+        if *is_fixed {
+            fixed.extend_from_slice(bytes);
+        } else {
+            fixed.extend_from_slice(&(offset as u32).to_le_bytes());
+            variable.extend_from_slice(bytes);
+            offset += bytes.len();
+        }
+    }
+    fixed.extend_from_slice(&variable);
+    fixed
+}
+
+/***************************************************************************************************
+ * decode
+ **************************************************************************************************/
+
+#[derive(Debug)]
+enum SszDecodeError {
+    Malformed,
+    UnsupportedType,
+}
+
+impl From<SszDecodeError> for u64 {
+    fn from(e: SszDecodeError) -> u64 {
+        match e {
+            SszDecodeError::Malformed => E_SSZ_MALFORMED_INPUT,
+            SszDecodeError::UnsupportedType => E_SSZ_UNSUPPORTED_TYPE,
+        }
+    }
+}
+
+fn uleb128_encode(mut len: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let mut byte = (len & 0x7f) as u8;
+        len >>= 7;
+        if len != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+    out
+}
+
+/// The fixed SSZ byte width of `layout`, or `None` if it's variable-size
+/// (a `List`, or a struct containing one).
+fn ssz_fixed_size(layout: &MoveTypeLayout) -> Option<usize> {
+    match layout {
+        MoveTypeLayout::Bool | MoveTypeLayout::U8 => Some(1),
+        MoveTypeLayout::U16 => Some(2),
+        MoveTypeLayout::U32 => Some(4),
+        MoveTypeLayout::U64 => Some(8),
+        MoveTypeLayout::U128 => Some(16),
+        MoveTypeLayout::U256 => Some(32),
+        MoveTypeLayout::Address => Some(32),
+        MoveTypeLayout::Vector(_) => None,
+        MoveTypeLayout::Struct(s) => {
+            let mut total = 0usize;
+            for field in s.fields(None) {
+                total = total.checked_add(ssz_fixed_size(&field)?)?;
+            }
+            Some(total)
+        },
+        _ => None,
+    }
+}
+
+/// Splits a container's encoded body back into per-part byte spans using
+/// the SSZ offset scheme: each `None` entry in `kinds` is a 4-byte
+/// little-endian offset (from the start of the body) to a variable part
+/// whose end is the next offset (or the end of the body); each `Some(n)`
+/// entry is `n` inline bytes.
+fn ssz_split_parts(bytes: &[u8], kinds: &[Option<usize>]) -> Result<Vec<Vec<u8>>, SszDecodeError> {
+    let mut parts: Vec<Option<Vec<u8>>> = vec![None; kinds.len()];
+    let mut var_offsets = Vec::new();
+    let mut cursor = 0usize;
+    for (i, kind) in kinds.iter().enumerate() {
+        match kind {
+            Some(size) => {
+                let end = cursor.checked_add(*size).ok_or(SszDecodeError::Malformed)?;
+                let slice = bytes.get(cursor..end).ok_or(SszDecodeError::Malformed)?;
+                parts[i] = Some(slice.to_vec());
+                cursor = end;
+            },
+            None => {
+                let end = cursor.checked_add(4).ok_or(SszDecodeError::Malformed)?;
+                let offset_bytes = bytes.get(cursor..end).ok_or(SszDecodeError::Malformed)?;
+                let offset = u32::from_le_bytes(offset_bytes.try_into().unwrap()) as usize;
+                var_offsets.push((i, offset));
+                cursor = end;
+            },
+        }
+    }
+    for w in 0..var_offsets.len() {
+        let (i, start) = var_offsets[w];
+        let end = var_offsets
+            .get(w + 1)
+            .map(|(_, next)| *next)
+            .unwrap_or(bytes.len());
+        if start > end || end > bytes.len() {
+            return Err(SszDecodeError::Malformed);
+        }
+        parts[i] = Some(bytes[start..end].to_vec());
+    }
+    parts
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .ok_or(SszDecodeError::Malformed)
+}
+
+/// Splits a `List` body of unknown element count back into elements: the
+/// element count is implied by the first offset (the fixed/offset section
+/// is exactly `count * 4` bytes).
+fn ssz_split_list_elements(bytes: &[u8]) -> Result<Vec<Vec<u8>>, SszDecodeError> {
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    if bytes.len() < 4 {
+        return Err(SszDecodeError::Malformed);
+    }
+    let first_offset = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+    if first_offset == 0 || first_offset % 4 != 0 || first_offset > bytes.len() {
+        return Err(SszDecodeError::Malformed);
+    }
+    let count = first_offset / 4;
+    let offsets: Vec<usize> = (0..count)
+        .map(|i| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap()) as usize)
+        .collect();
+
+    let mut items = Vec::with_capacity(count);
+    for i in 0..count {
+        let start = offsets[i];
+        let end = offsets.get(i + 1).copied().unwrap_or(bytes.len());
+        if start > end || end > bytes.len() {
+            return Err(SszDecodeError::Malformed);
+        }
+        items.push(bytes[start..end].to_vec());
+    }
+    Ok(items)
+}
+
+/// Recursively decodes `bytes` against `layout`, returning the equivalent
+/// BCS encoding (fixed-width little-endian ints already coincide between
+/// SSZ and BCS; vectors/structs go through their own offset/field
+/// splitting since SSZ's offset table has no BCS equivalent).
+fn ssz_decode_value(bytes: &[u8], layout: &MoveTypeLayout) -> Result<Vec<u8>, SszDecodeError> {
+    match layout {
+        MoveTypeLayout::Bool => match bytes {
+            [0] => Ok(vec![0]),
+            [1] => Ok(vec![1]),
+            _ => Err(SszDecodeError::Malformed),
+        },
+        MoveTypeLayout::U8
+        | MoveTypeLayout::U16
+        | MoveTypeLayout::U32
+        | MoveTypeLayout::U64
+        | MoveTypeLayout::U128
+        | MoveTypeLayout::U256
+        | MoveTypeLayout::Address => {
+            let width = ssz_fixed_size(layout).unwrap();
+            if bytes.len() != width {
+                return Err(SszDecodeError::Malformed);
+            }
+            Ok(bytes.to_vec())
+        },
+        MoveTypeLayout::Vector(elem) => {
+            if let Some(elem_size) = ssz_fixed_size(elem) {
+                if elem_size == 0 || bytes.len() % elem_size != 0 {
+                    return Err(SszDecodeError::Malformed);
+                }
+                let count = bytes.len() / elem_size;
+                let mut out = uleb128_encode(count);
+                for chunk in bytes.chunks(elem_size) {
+                    out.extend_from_slice(&ssz_decode_value(chunk, elem)?);
+                }
+                Ok(out)
+            } else {
+                let items = ssz_split_list_elements(bytes)?;
+                let mut out = uleb128_encode(items.len());
+                for item in &items {
+                    out.extend_from_slice(&ssz_decode_value(item, elem)?);
+                }
+                Ok(out)
+            }
+        },
+        MoveTypeLayout::Struct(struct_layout) => {
+            let field_layouts = struct_layout.fields(None);
+            let kinds: Vec<Option<usize>> = field_layouts.iter().map(ssz_fixed_size).collect();
+            let parts = ssz_split_parts(bytes, &kinds)?;
+            let mut out = Vec::new();
+            for (part, field_layout) in parts.iter().zip(field_layouts.iter()) {
+                out.extend_from_slice(&ssz_decode_value(part, field_layout)?);
+            }
+            Ok(out)
+        },
+        _ => Err(SszDecodeError::UnsupportedType),
+    }
+}
+
+/***************************************************************************************************
+ * merkleization
+ **************************************************************************************************/
+
+fn sha256_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Chunks `data` into 32-byte leaves (zero-padded), pads the leaf count up
+/// to the next power of two with zero chunks, and hashes pairs bottom-up.
+fn merkleize(data: &[u8]) -> [u8; 32] {
+    let mut leaves: Vec<[u8; 32]> = data
+        .chunks(32)
+        .map(|chunk| {
+            let mut leaf = [0u8; 32];
+            leaf[..chunk.len()].copy_from_slice(chunk);
+            leaf
+        })
+        .collect();
+    if leaves.is_empty() {
+        leaves.push([0u8; 32]);
+    }
+
+    let leaf_count = leaves.len().next_power_of_two();
+    leaves.resize(leaf_count, [0u8; 32]);
+
+    while leaves.len() > 1 {
+        leaves = leaves
+            .chunks(2)
+            .map(|pair| sha256_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    leaves[0]
+}
+
+fn mix_in_length(root: [u8; 32], len: usize) -> [u8; 32] {
+    let mut len_chunk = [0u8; 32];
+    len_chunk[..8].copy_from_slice(&(len as u64).to_le_bytes());
+    sha256_pair(&root, &len_chunk)
+}
+
+fn ssz_hash_tree_root_value(val: &Value, layout: &MoveTypeLayout) -> SafeNativeResult<[u8; 32]> {
+    match layout {
+        MoveTypeLayout::Vector(elem_layout) => {
+            let parts = ssz_encode_vector_parts(val, elem_layout)?;
+            let encoded = ssz_serialize_parts(&parts);
+            let root = merkleize(&encoded);
+            Ok(mix_in_length(root, parts.len()))
+        },
+        _ => {
+            let encoded = ssz_encode_value(val, layout)?;
+            Ok(merkleize(&encoded))
+        },
+    }
+}
+
+/***************************************************************************************************
+ * natives
+ **************************************************************************************************/
+
+fn native_ssz_encode(
+    context: &mut SafeNativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 1);
+    safely_assert_eq!(args.len(), 1);
+
+    let val_type: Type = ty_args.pop().unwrap();
+    let v = safely_pop_arg!(args, Reference);
+    let val = v.read_ref()?;
+
+    let layout = context.type_to_type_layout(&val_type)?;
+    let encoded = ssz_encode_value(&val, &layout)?;
+
+    context.charge(SSZ_ENCODE_BASE + SSZ_ENCODE_PER_BYTE * NumBytes::new(encoded.len() as u64))?;
+
+    Ok(smallvec![Value::vector_u8(encoded)])
+}
+
+fn native_ssz_decode(
+    context: &mut SafeNativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 1);
+    safely_assert_eq!(args.len(), 1);
+
+    let val_type: Type = ty_args.pop().unwrap();
+    let bytes = safely_pop_arg!(args, Vec<u8>);
+
+    context.charge(SSZ_DECODE_BASE + SSZ_DECODE_PER_BYTE * NumBytes::new(bytes.len() as u64))?;
+
+    let layout = context.type_to_type_layout(&val_type)?;
+    // Resolve SSZ's offset-based variable-size encoding into genuine BCS
+    // bytes, then reuse `simple_deserialize` as the final packing step.
+    let bcs_bytes = ssz_decode_value(&bytes, &layout)
+        .map_err(|e| SafeNativeError::Abort { abort_code: e.into() })?;
+    let decoded = Value::simple_deserialize(&bcs_bytes, &layout).ok_or(SafeNativeError::Abort {
+        abort_code: E_SSZ_MALFORMED_INPUT,
+    })?;
+
+    Ok(smallvec![decoded])
+}
+
+fn native_ssz_hash_tree_root(
+    context: &mut SafeNativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(ty_args.len(), 1);
+    safely_assert_eq!(args.len(), 1);
+
+    let val_type: Type = ty_args.pop().unwrap();
+    let v = safely_pop_arg!(args, Reference);
+    let val = v.read_ref()?;
+
+    let layout = context.type_to_type_layout(&val_type)?;
+    let encoded_len_hint = ssz_encode_value(&val, &layout)?.len();
+    context.charge(
+        SSZ_HASH_TREE_ROOT_BASE
+            + SSZ_HASH_TREE_ROOT_PER_BYTE * NumBytes::new(encoded_len_hint as u64),
+    )?;
+
+    let root = ssz_hash_tree_root_value(&val, &layout)?;
+    Ok(smallvec![Value::vector_u8(root.to_vec())])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        ("ssz_encode", native_ssz_encode as RawSafeNative),
+        ("ssz_decode", native_ssz_decode),
+        ("ssz_hash_tree_root", native_ssz_hash_tree_root),
+    ];
+
+    builder.make_named_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_fixed_primitive() {
+        let value: u32 = 0xdead_beef;
+        let bcs_bytes = ssz_decode_value(&value.to_le_bytes(), &MoveTypeLayout::U32).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&value).unwrap());
+    }
+
+    #[test]
+    fn decode_vector_of_fixed_elements() {
+        let elems: Vec<u64> = vec![1, 2, 3];
+        let bytes: Vec<u8> = elems.iter().flat_map(|e| e.to_le_bytes()).collect();
+        let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U64));
+        let bcs_bytes = ssz_decode_value(&bytes, &layout).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&elems).unwrap());
+    }
+
+    #[test]
+    fn decode_list_of_variable_elements_round_trips_through_split_and_serialize() {
+        let elems: Vec<Vec<u8>> = vec![vec![1, 2, 3], vec![], vec![4]];
+        let parts: Vec<(bool, Vec<u8>)> =
+            elems.iter().map(|e| (false, e.clone())).collect();
+        let encoded = ssz_serialize_parts(&parts);
+
+        let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Vector(Box::new(
+            MoveTypeLayout::U8,
+        ))));
+        let bcs_bytes = ssz_decode_value(&encoded, &layout).unwrap();
+        assert_eq!(bcs_bytes, bcs::to_bytes(&elems).unwrap());
+    }
+
+    #[test]
+    fn split_parts_resolves_mixed_fixed_and_variable_fields() {
+        // field 0: fixed 2 bytes, field 1: variable, field 2: fixed 1 byte.
+        let kinds = [Some(2), None, Some(1)];
+        let mut bytes = vec![0xaa, 0xbb]; // field 0
+        bytes.extend_from_slice(&(7u32).to_le_bytes()); // offset to field 1 (after 7-byte fixed section)
+        bytes.push(0xff); // field 2
+        bytes.extend_from_slice(&[1, 2, 3]); // field 1's body
+
+        let parts = ssz_split_parts(&bytes, &kinds).unwrap();
+        assert_eq!(parts[0], vec![0xaa, 0xbb]);
+        assert_eq!(parts[1], vec![1, 2, 3]);
+        assert_eq!(parts[2], vec![0xff]);
+    }
+
+    #[test]
+    fn split_parts_rejects_out_of_bounds_offset() {
+        let kinds = [None];
+        let bytes = (100u32).to_le_bytes().to_vec(); // offset far past the buffer
+        assert!(matches!(
+            ssz_split_parts(&bytes, &kinds),
+            Err(SszDecodeError::Malformed)
+        ));
+    }
+
+    #[test]
+    fn split_list_elements_rejects_misaligned_first_offset() {
+        // first_offset must be a multiple of 4 (the offset table's width).
+        let bytes = (5u32).to_le_bytes().to_vec();
+        assert!(matches!(
+            ssz_split_list_elements(&bytes),
+            Err(SszDecodeError::Malformed)
+        ));
+    }
+
+    #[test]
+    fn merkleize_empty_is_zero_leaf() {
+        assert_eq!(merkleize(&[]), [0u8; 32]);
+    }
+
+    #[test]
+    fn mix_in_length_changes_with_length() {
+        let root = [1u8; 32];
+        assert_ne!(mix_in_length(root, 0), mix_in_length(root, 1));
+    }
+}